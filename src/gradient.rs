@@ -1,18 +1,324 @@
 use gpui::{hsla, px, Hsla, Pixels, Point, RenderImage, Size};
-use image::{Frame, ImageBuffer};
+use image::{Frame, ImageBuffer, Rgba};
+use rayon::prelude::*;
 use smallvec::SmallVec;
+use std::f32::consts::PI;
+
+/// Number of steps in the precomputed color ramp `render` samples from.
+/// 1024 is dense enough that adjacent ramp entries are visually
+/// indistinguishable even for sharp multi-stop gradients.
+const RAMP_RESOLUTION: usize = 1024;
+
+/// Color space in which two stops are blended, mirroring the CSS
+/// `<color-interpolation-method>` grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColorSpace {
+    /// Componentwise blending of the gamma-encoded sRGB channels. Cheap but
+    /// muddies mid-tones, e.g. a red-to-green stop dips through a dark brown.
+    #[default]
+    Srgb,
+    /// Blends in linear light (gamma-correct), fixing the dark-banding
+    /// muddiness `Srgb` produces between saturated complementary stops.
+    LinearSrgb,
+    Oklab,
+    Oklch,
+}
+
+/// Which way around the hue wheel a polar color space should travel,
+/// mirroring CSS `<hue-interpolation-method>`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HueInterpolation {
+    /// Travel the ≤180° arc between the two hues (the default).
+    #[default]
+    Shorter,
+    /// Travel the ≥180° arc between the two hues.
+    Longer,
+    /// Always travel clockwise (increasing hue angle), wrapping past 360°.
+    Increasing,
+    /// Always travel counterclockwise (decreasing hue angle), wrapping past 0°.
+    Decreasing,
+}
+
+/// Adjusts `h2` (in turns) so that lerping `h1..=h2` travels around the hue
+/// wheel in the direction requested by `mode`, then wraps the result back
+/// into `[0, 1)`.
+fn interpolate_hue(h1: f32, h2: f32, t: f32, mode: HueInterpolation) -> f32 {
+    let mut h2 = h2;
+    let d = h2 - h1;
+    match mode {
+        HueInterpolation::Shorter => {
+            if d > 0.5 {
+                h2 -= 1.0;
+            } else if d < -0.5 {
+                h2 += 1.0;
+            }
+        }
+        HueInterpolation::Longer => {
+            if (0.0..=0.5).contains(&d) {
+                h2 -= 1.0;
+            } else if (-0.5..0.0).contains(&d) {
+                h2 += 1.0;
+            }
+        }
+        HueInterpolation::Increasing => {
+            if d < 0.0 {
+                h2 += 1.0;
+            }
+        }
+        HueInterpolation::Decreasing => {
+            if d > 0.0 {
+                h2 -= 1.0;
+            }
+        }
+    }
+    let h = h1 * (1.0 - t) + h2 * t;
+    h.rem_euclid(1.0)
+}
+
+/// Lerps premultiplied `(r, g, b)` channels so that blending towards a
+/// transparent stop doesn't pull in its (irrelevant) color, then
+/// un-premultiplies the result.
+fn lerp_premultiplied(
+    c0: (f32, f32, f32),
+    a0: f32,
+    c1: (f32, f32, f32),
+    a1: f32,
+    t: f32,
+) -> ((f32, f32, f32), f32) {
+    let a = a0 * (1.0 - t) + a1 * t;
+    let pm0 = (c0.0 * a0, c0.1 * a0, c0.2 * a0);
+    let pm1 = (c1.0 * a1, c1.1 * a1, c1.2 * a1);
+    let pm = (
+        pm0.0 * (1.0 - t) + pm1.0 * t,
+        pm0.1 * (1.0 - t) + pm1.1 * t,
+        pm0.2 * (1.0 - t) + pm1.2 * t,
+    );
+    if a <= 1e-4 {
+        (c0, a)
+    } else {
+        ((pm.0 / a, pm.1 / a, pm.2 / a), a)
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    let c = c.clamp(0.0, 1.0);
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts linear-light sRGB into OKLab, per Björn Ottosson's reference
+/// implementation.
+fn linear_srgb_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let l = 0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b;
+    let m = 0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b;
+    let s = 0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b;
+
+    let l_ = l.cbrt();
+    let m_ = m.cbrt();
+    let s_ = s.cbrt();
+
+    (
+        0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_,
+        1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_,
+        0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_,
+    )
+}
+
+fn oklab_to_linear_srgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+    let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+    let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    (
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    )
+}
+
+fn srgb_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    linear_srgb_to_oklab(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b))
+}
+
+fn oklab_to_srgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let (r, g, b) = oklab_to_linear_srgb(l, a, b);
+    (linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+fn hsl_to_rgb_f32(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    if s == 0.0 {
+        (l, l, l)
+    } else {
+        let q = if l < 0.5 {
+            l * (1.0 + s)
+        } else {
+            l + s - l * s
+        };
+        let p = 2.0 * l - q;
+        (
+            hue_to_rgb(p, q, h + 1.0 / 3.0),
+            hue_to_rgb(p, q, h),
+            hue_to_rgb(p, q, h - 1.0 / 3.0),
+        )
+    }
+}
+
+fn rgb_f32_to_hsla(r: f32, g: f32, b: f32, a: f32) -> Hsla {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+
+    if (max - min).abs() < 1e-6 {
+        return hsla(0.0, 0.0, l, a);
+    }
+
+    let d = max - min;
+    let s = if l > 0.5 {
+        d / (2.0 - max - min)
+    } else {
+        d / (max + min)
+    };
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    } / 6.0;
+
+    hsla(h, s, l, a)
+}
+
+fn hue_to_rgb(p: f32, q: f32, t: f32) -> f32 {
+    let mut t = t;
+    if t < 0.0 {
+        t += 1.0;
+    }
+    if t > 1.0 {
+        t -= 1.0;
+    }
+    if t < 1.0 / 6.0 {
+        return p + (q - p) * 6.0 * t;
+    }
+    if t < 1.0 / 2.0 {
+        return q;
+    }
+    if t < 2.0 / 3.0 {
+        return p + (q - p) * (2.0 / 3.0 - t) * 6.0;
+    }
+    p
+}
+
+/// Solves for the gradient offset `t` of pixel `(px, py)` across a
+/// two-circle radial gradient: the circle centered on `lerp(c0, c1, t)`
+/// with radius `lerp(r0, r1, t)` that the pixel lies on. This reduces to
+/// `(dist - r0) / (r1 - r0)` for the common concentric case, but also
+/// supports offset focal points per the CSS `radial-gradient()` model.
+fn solve_two_circle_t(
+    px: f32,
+    py: f32,
+    c0x: f32,
+    c0y: f32,
+    r0: f32,
+    c1x: f32,
+    c1y: f32,
+    r1: f32,
+) -> f32 {
+    let dx = c1x - c0x;
+    let dy = c1y - c0y;
+    let dr = r1 - r0;
+    let qx = px - c0x;
+    let qy = py - c0y;
+
+    let a = dx * dx + dy * dy - dr * dr;
+    let b = -2.0 * (qx * dx + qy * dy + r0 * dr);
+    let c = qx * qx + qy * qy - r0 * r0;
+
+    if a.abs() < 1e-6 {
+        return if b.abs() < 1e-6 { 0.0 } else { -c / b };
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return 0.0;
+    }
+    let sqrt_discriminant = discriminant.sqrt();
+    let t0 = (-b + sqrt_discriminant) / (2.0 * a);
+    let t1 = (-b - sqrt_discriminant) / (2.0 * a);
+
+    // The circle's radius must stay non-negative along the swept line;
+    // among the valid roots, the larger `t` is the one CSS renderers use.
+    [t0, t1]
+        .into_iter()
+        .filter(|t| r0 + t * dr >= 0.0)
+        .fold(None, |best: Option<f32>, t| Some(best.map_or(t, |b| b.max(t))))
+        .unwrap_or(0.0)
+}
 
 trait HslaExt {
-    fn interpolate(&self, other: Hsla, t: f32) -> Hsla;
+    fn interpolate_in(&self, other: Hsla, t: f32, color_space: ColorSpace, hue: HueInterpolation) -> Hsla;
 }
 
 impl HslaExt for Hsla {
-    fn interpolate(&self, other: Hsla, t: f32) -> Hsla {
-        let h = self.h * (1.0 - t) + other.h * t;
-        let s = self.s * (1.0 - t) + other.s * t;
-        let l = self.l * (1.0 - t) + other.l * t;
-        let a = self.a * (1.0 - t) + other.a * t;
-        hsla(h, s, l, a)
+    fn interpolate_in(&self, other: Hsla, t: f32, color_space: ColorSpace, hue: HueInterpolation) -> Hsla {
+        let (r0, g0, b0) = hsl_to_rgb_f32(self.h, self.s, self.l);
+        let (r1, g1, b1) = hsl_to_rgb_f32(other.h, other.s, other.l);
+
+        let (r, g, b, a) = match color_space {
+            ColorSpace::Srgb => {
+                let (c, a) = lerp_premultiplied((r0, g0, b0), self.a, (r1, g1, b1), other.a, t);
+                (c.0, c.1, c.2, a)
+            }
+            ColorSpace::LinearSrgb => {
+                let l0 = (srgb_to_linear(r0), srgb_to_linear(g0), srgb_to_linear(b0));
+                let l1 = (srgb_to_linear(r1), srgb_to_linear(g1), srgb_to_linear(b1));
+                let (c, a) = lerp_premultiplied(l0, self.a, l1, other.a, t);
+                (
+                    linear_to_srgb(c.0),
+                    linear_to_srgb(c.1),
+                    linear_to_srgb(c.2),
+                    a,
+                )
+            }
+            ColorSpace::Oklab => {
+                let lab0 = srgb_to_oklab(r0, g0, b0);
+                let lab1 = srgb_to_oklab(r1, g1, b1);
+                let (c, a) = lerp_premultiplied(lab0, self.a, lab1, other.a, t);
+                let (r, g, b) = oklab_to_srgb(c.0, c.1, c.2);
+                (r, g, b, a)
+            }
+            ColorSpace::Oklch => {
+                let (l0, a0_, b0_) = srgb_to_oklab(r0, g0, b0);
+                let (l1, a1_, b1_) = srgb_to_oklab(r1, g1, b1);
+                let c0 = (a0_ * a0_ + b0_ * b0_).sqrt();
+                let c1 = (a1_ * a1_ + b1_ * b1_).sqrt();
+                let h0 = (b0_.atan2(a0_) / (2.0 * PI)).rem_euclid(1.0);
+                let h1 = (b1_.atan2(a1_) / (2.0 * PI)).rem_euclid(1.0);
+
+                let (lc, a) = lerp_premultiplied((l0, c0, 0.0), self.a, (l1, c1, 0.0), other.a, t);
+                let h = interpolate_hue(h0, h1, t, hue);
+                let theta = h * 2.0 * PI;
+                let (r, g, b) = oklab_to_srgb(lc.0, lc.1 * theta.cos(), lc.1 * theta.sin());
+                (r, g, b, a)
+            }
+        };
+
+        rgb_f32_to_hsla(r, g, b, a)
     }
 }
 
@@ -30,6 +336,21 @@ pub enum GradientType {
     #[default]
     Linear,
     RepeatingLinear,
+    /// A two-circle radial gradient sweeping from the circle
+    /// `(start, start_radius)` to `(end, end_radius)`, matching the CSS
+    /// `radial-gradient()` focal/two-circle form. A simple concentric
+    /// radial is just `start == end`.
+    Radial,
+    /// Like `Radial`, but the stop pattern tiles outward past the end circle,
+    /// repeating over the span between the first and last stop (see
+    /// `wrap_repeating`), instead of clamping to the last stop.
+    RepeatingRadial,
+    /// A conic gradient sweeping around `start` starting from
+    /// `start_angle`, matching CSS `conic-gradient()`.
+    Conic,
+    /// Like `Conic`, but the stop pattern tiles around the sweep, repeating
+    /// over the span between the first and last stop (see `wrap_repeating`).
+    RepeatingConic,
 }
 
 #[derive(Clone, Copy, Default)]
@@ -51,12 +372,361 @@ pub enum AngleOrCorner {
     Side(GradientSide),
 }
 
-#[derive(Default)]
+/// Whether a box-relative radial gradient (`radial_shaped`) is forced to a
+/// circle or allowed to stretch into an ellipse matching the box's aspect
+/// ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RadialShape {
+    #[default]
+    Circle,
+    Ellipse,
+}
+
+/// CSS-style radial gradient sizing: either a keyword relative to the box
+/// the gradient fills, or an explicit ellipse radius.
+#[derive(Clone, Copy)]
+pub enum RadialSize {
+    ClosestSide,
+    FarthestSide,
+    ClosestCorner,
+    FarthestCorner,
+    Ellipse { rx: Pixels, ry: Pixels },
+}
+
+/// Resolves a `RadialSize` against `center` and the box the gradient
+/// fills, returning independent `(rx, ry)` end-circle radii so an
+/// elliptical gradient can stretch per axis instead of collapsing to a
+/// circle. For the `-corner` keywords, the corner is picked by Euclidean
+/// distance, but `rx`/`ry` are that corner's horizontal/vertical offsets
+/// from `center`, not the scalar distance to it.
+fn resolve_radial_size(center: Point<Pixels>, size: RadialSize, box_size: Size<Pixels>) -> (Pixels, Pixels) {
+    if let RadialSize::Ellipse { rx, ry } = size {
+        return (rx, ry);
+    }
+
+    let width = box_size.width;
+    let height = box_size.height;
+    let dist_left = center.x;
+    let dist_right = width - center.x;
+    let dist_top = center.y;
+    let dist_bottom = height - center.y;
+
+    let corners: [(Pixels, Pixels); 4] = [
+        (dist_left, dist_top),
+        (dist_right, dist_top),
+        (dist_left, dist_bottom),
+        (dist_right, dist_bottom),
+    ];
+    let corner_dist_sq = |(dx, dy): (Pixels, Pixels)| dx.0.powi(2) + dy.0.powi(2);
+
+    match size {
+        RadialSize::ClosestSide => (dist_left.min(dist_right), dist_top.min(dist_bottom)),
+        RadialSize::FarthestSide => (dist_left.max(dist_right), dist_top.max(dist_bottom)),
+        RadialSize::ClosestCorner => corners
+            .into_iter()
+            .min_by(|a, b| corner_dist_sq(*a).total_cmp(&corner_dist_sq(*b)))
+            .unwrap(),
+        RadialSize::FarthestCorner => corners
+            .into_iter()
+            .max_by(|a, b| corner_dist_sq(*a).total_cmp(&corner_dist_sq(*b)))
+            .unwrap(),
+        RadialSize::Ellipse { .. } => unreachable!(),
+    }
+}
+
+/// Splits `s` on top-level commas, ignoring commas nested inside `(...)`
+/// (e.g. the ones separating `rgb()`'s own arguments from the stop list).
+fn split_top_level_commas(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Parses a CSS `<angle>` (`deg`, `rad`, `grad`, or `turn`) into degrees in
+/// CSS's own convention (`0deg` points up, increasing clockwise). Returns
+/// `None` if `s` isn't an angle, so callers can fall back to treating it
+/// as a color stop.
+fn parse_css_angle(s: &str) -> Option<f32> {
+    let s = s.trim();
+    for (suffix, unit_to_degrees) in [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("turn", 360.0),
+        ("rad", 180.0 / PI),
+    ] {
+        if let Some(number) = s.strip_suffix(suffix) {
+            return number.trim().parse::<f32>().ok().map(|n| n * unit_to_degrees);
+        }
+    }
+    None
+}
+
+/// Parses the side-or-corner keywords following CSS `to `, e.g. `"top"` or
+/// `"top right"` (either word order).
+fn parse_side_or_corner(s: &str) -> Result<GradientSide, String> {
+    let mut has_top = false;
+    let mut has_bottom = false;
+    let mut has_left = false;
+    let mut has_right = false;
+    for word in s.split_whitespace() {
+        match word {
+            "top" => has_top = true,
+            "bottom" => has_bottom = true,
+            "left" => has_left = true,
+            "right" => has_right = true,
+            other => return Err(format!("unknown direction keyword `{other}`")),
+        }
+    }
+    match (has_top, has_bottom, has_left, has_right) {
+        (true, false, false, false) => Ok(GradientSide::Top),
+        (false, true, false, false) => Ok(GradientSide::Bottom),
+        (false, false, true, false) => Ok(GradientSide::Left),
+        (false, false, false, true) => Ok(GradientSide::Right),
+        (true, false, true, false) => Ok(GradientSide::TopLeft),
+        (true, false, false, true) => Ok(GradientSide::TopRight),
+        (false, true, true, false) => Ok(GradientSide::BottomLeft),
+        (false, true, false, true) => Ok(GradientSide::BottomRight),
+        _ => Err(format!("invalid direction `to {s}`")),
+    }
+}
+
+/// Looks up a CSS named color (the common subset, not the full 148-name
+/// list) as `(r, g, b)` bytes.
+fn css_named_color(name: &str) -> Option<(u8, u8, u8)> {
+    Some(match name {
+        "black" => (0, 0, 0),
+        "white" => (255, 255, 255),
+        "red" => (255, 0, 0),
+        "green" => (0, 128, 0),
+        "lime" => (0, 255, 0),
+        "blue" => (0, 0, 255),
+        "yellow" => (255, 255, 0),
+        "cyan" | "aqua" => (0, 255, 255),
+        "magenta" | "fuchsia" => (255, 0, 255),
+        "orange" => (255, 165, 0),
+        "purple" => (128, 0, 128),
+        "pink" => (255, 192, 203),
+        "brown" => (165, 42, 42),
+        "gray" | "grey" => (128, 128, 128),
+        "silver" => (192, 192, 192),
+        "gold" => (255, 215, 0),
+        "indigo" => (75, 0, 130),
+        "violet" => (238, 130, 238),
+        "navy" => (0, 0, 128),
+        "teal" => (0, 128, 128),
+        "maroon" => (128, 0, 0),
+        "olive" => (128, 128, 0),
+        _ => return None,
+    })
+}
+
+/// Parses one hex digit pair (`"ff"` -> `255`).
+fn parse_hex_byte(s: &str) -> Option<u8> {
+    u8::from_str_radix(s, 16).ok()
+}
+
+/// Parses a CSS color into `Hsla`: named colors, `#rgb`/`#rrggbb`/
+/// `#rrggbbaa` hex, or `rgb()`/`rgba()`/`hsl()`/`hsla()` functions.
+fn parse_css_color(s: &str) -> Result<Hsla, String> {
+    let s = s.trim();
+
+    if let Some(hex) = s.strip_prefix('#') {
+        let (r, g, b, a) = match hex.len() {
+            3 => (
+                parse_hex_byte(&hex[0..1].repeat(2)),
+                parse_hex_byte(&hex[1..2].repeat(2)),
+                parse_hex_byte(&hex[2..3].repeat(2)),
+                Some(255),
+            ),
+            6 => (
+                parse_hex_byte(&hex[0..2]),
+                parse_hex_byte(&hex[2..4]),
+                parse_hex_byte(&hex[4..6]),
+                Some(255),
+            ),
+            8 => (
+                parse_hex_byte(&hex[0..2]),
+                parse_hex_byte(&hex[2..4]),
+                parse_hex_byte(&hex[4..6]),
+                parse_hex_byte(&hex[6..8]),
+            ),
+            _ => return Err(format!("invalid hex color `#{hex}`")),
+        };
+        let (r, g, b, a) = (
+            r.ok_or_else(|| format!("invalid hex color `#{hex}`"))?,
+            g.ok_or_else(|| format!("invalid hex color `#{hex}`"))?,
+            b.ok_or_else(|| format!("invalid hex color `#{hex}`"))?,
+            a.ok_or_else(|| format!("invalid hex color `#{hex}`"))?,
+        );
+        return Ok(rgb_f32_to_hsla(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        ));
+    }
+
+    if let Some(args) = s.strip_prefix("rgba(").or_else(|| s.strip_prefix("rgb(")) {
+        let args = args
+            .strip_suffix(')')
+            .ok_or_else(|| format!("missing closing `)` in `{s}`"))?;
+        let components: Vec<f32> = args
+            .split(',')
+            .map(|c| c.trim().parse::<f32>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("invalid rgb() component in `{s}`"))?;
+        let [r, g, b]: [f32; 3] = components
+            .get(0..3)
+            .and_then(|c| c.try_into().ok())
+            .ok_or_else(|| format!("rgb() needs 3 components in `{s}`"))?;
+        let a = components.get(3).copied().unwrap_or(1.0);
+        return Ok(rgb_f32_to_hsla(r / 255.0, g / 255.0, b / 255.0, a));
+    }
+
+    if let Some(args) = s.strip_prefix("hsla(").or_else(|| s.strip_prefix("hsl(")) {
+        let args = args
+            .strip_suffix(')')
+            .ok_or_else(|| format!("missing closing `)` in `{s}`"))?;
+        let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        if parts.len() < 3 {
+            return Err(format!("hsl() needs at least 3 components in `{s}`"));
+        }
+        let h = parts[0]
+            .trim_end_matches("deg")
+            .parse::<f32>()
+            .map_err(|_| format!("invalid hsl() hue in `{s}`"))?;
+        let parse_percent = |p: &str| {
+            p.trim_end_matches('%')
+                .parse::<f32>()
+                .map_err(|_| format!("invalid hsl() component in `{s}`"))
+        };
+        let sat = parse_percent(parts[1])?;
+        let light = parse_percent(parts[2])?;
+        let alpha = parts
+            .get(3)
+            .map(|a| a.parse::<f32>())
+            .transpose()
+            .map_err(|_| format!("invalid hsl() alpha in `{s}`"))?
+            .unwrap_or(1.0);
+        return Ok(hsla(h / 360.0, sat / 100.0, light / 100.0, alpha));
+    }
+
+    let (r, g, b) = css_named_color(s).ok_or_else(|| format!("unknown color `{s}`"))?;
+    Ok(rgb_f32_to_hsla(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        1.0,
+    ))
+}
+
+/// Parses one color-stop entry, e.g. `"red"`, `"blue 50%"`, or
+/// `"#00ff0080 100%"`: a color followed by an optional percentage
+/// position.
+fn parse_css_color_stop(s: &str) -> Result<ColorStop, String> {
+    let s = s.trim();
+    match s.rsplit_once(' ') {
+        Some((color, position)) if position.ends_with('%') => {
+            let percentage = position
+                .trim_end_matches('%')
+                .trim()
+                .parse::<f32>()
+                .map_err(|_| format!("invalid stop position `{position}`"))?
+                / 100.0;
+            Ok(color_stop(parse_css_color(color)?, Some(percentage)))
+        }
+        _ => Ok(color_stop(parse_css_color(s)?, None)),
+    }
+}
+
 pub struct Gradient {
     pub colors: Vec<ColorStop>,
     pub gradient_type: GradientType,
     pub start: Point<Pixels>,
     pub end: Point<Pixels>,
+    /// Color space stops are blended in, set via `with_color_space`.
+    /// Defaults to `Srgb` (componentwise gamma-encoded sRGB blending).
+    pub color_space: ColorSpace,
+    /// Direction polar color spaces (`Oklch`) travel around the hue wheel,
+    /// set via `with_hue_interpolation`.
+    pub hue_interpolation: HueInterpolation,
+    /// Radius of the circle centered on `start`, only meaningful for
+    /// `GradientType::Radial`/`RepeatingRadial`.
+    pub start_radius: Pixels,
+    /// Radius of the circle centered on `end`, only meaningful for
+    /// `GradientType::Radial`/`RepeatingRadial`.
+    pub end_radius: Pixels,
+    /// Ratio of vertical to horizontal radius (`ry / rx`) for elliptical
+    /// radial gradients produced via `radial_sized`. `1.0` is a circle.
+    pub aspect: f32,
+    /// Inverse of the affine transform applied to the gradient's coordinate
+    /// space, precomputed once by `with_transform` so `calculate_t` only has
+    /// to map each pixel through it.
+    pub transform: Option<[f32; 6]>,
+    /// Angle, in radians, the `Conic`/`RepeatingConic` sweep starts from.
+    /// Only meaningful for those gradient types.
+    pub start_angle: f32,
+    /// Size of the tile the gradient fills, and the transparent gap between
+    /// tiles, when set via `with_tile`. `render` repeats the gradient across
+    /// the full canvas at this period instead of stretching it to fill it.
+    pub tile: Option<(Size<Pixels>, Size<Pixels>)>,
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Self {
+            colors: Vec::new(),
+            gradient_type: GradientType::default(),
+            start: Point::default(),
+            end: Point::default(),
+            color_space: ColorSpace::default(),
+            hue_interpolation: HueInterpolation::default(),
+            start_radius: Pixels::default(),
+            end_radius: Pixels::default(),
+            aspect: 1.0,
+            transform: None,
+            start_angle: 0.0,
+            tile: None,
+        }
+    }
+}
+
+/// Inverts a 2x3 affine matrix `[a, b, c, d, e, f]` representing
+/// `x' = a*x + c*y + e`, `y' = b*x + d*y + f`. Returns `None` if the matrix
+/// is singular (zero determinant) rather than dividing by zero.
+fn invert_affine(m: [f32; 6]) -> Option<[f32; 6]> {
+    let [a, b, c, d, e, f] = m;
+    let det = a * d - b * c;
+    if det.abs() < 1e-6 {
+        return None;
+    }
+    let inv_det = 1.0 / det;
+    let ia = d * inv_det;
+    let ib = -b * inv_det;
+    let ic = -c * inv_det;
+    let id = a * inv_det;
+    let ie = -(ia * e + ic * f);
+    let if_ = -(ib * e + id * f);
+    Some([ia, ib, ic, id, ie, if_])
+}
+
+/// Maps `(x, y)` through the affine matrix `[a, b, c, d, e, f]`.
+fn apply_affine(m: [f32; 6], x: f32, y: f32) -> (f32, f32) {
+    let [a, b, c, d, e, f] = m;
+    (a * x + c * y + e, b * x + d * y + f)
 }
 
 impl Gradient {
@@ -68,27 +738,253 @@ impl Gradient {
         }
     }
 
-    pub fn render(&self, size: Size<Pixels>) -> RenderImage {
-        let width = size.width.0;
-        let height = size.height.0;
-
-        let mut img = ImageBuffer::new(width as u32, height as u32);
-        for (x, y, pixel) in img.enumerate_pixels_mut() {
-            let color = self.calculate_color(Point {
-                x: px(x as f32),
-                y: px(y as f32),
-            });
-            let rgba = color.to_rgb();
+    /// Sets the color space stops are blended in (default `Srgb`,
+    /// componentwise blending of gamma-encoded sRGB channels).
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Sets which direction around the hue wheel `Oklch` stops travel.
+    pub fn with_hue_interpolation(mut self, hue_interpolation: HueInterpolation) -> Self {
+        self.hue_interpolation = hue_interpolation;
+        self
+    }
+
+    /// Builds a two-circle radial gradient: the gradient sweeps from the
+    /// circle `(start, start_radius)` to `(end, end_radius)`, matching the
+    /// CSS `radial-gradient()` focal/two-circle form. A simple concentric
+    /// radial is just `start == end`.
+    pub fn radial(
+        start: Point<Pixels>,
+        start_radius: Pixels,
+        end: Point<Pixels>,
+        end_radius: Pixels,
+        colors: Vec<ColorStop>,
+    ) -> Self {
+        Self {
+            colors,
+            gradient_type: GradientType::Radial,
+            start,
+            end,
+            start_radius,
+            end_radius,
+            ..Default::default()
+        }
+    }
+
+    /// Builds a concentric radial gradient sized with a CSS-style keyword
+    /// (`closest-side`, `farthest-corner`, ...) or an explicit ellipse,
+    /// relative to the box the gradient fills.
+    pub fn radial_sized(
+        center: Point<Pixels>,
+        size: RadialSize,
+        colors: Vec<ColorStop>,
+        box_size: Size<Pixels>,
+    ) -> Self {
+        let (rx, ry) = resolve_radial_size(center, size, box_size);
+        let mut gradient = Self::radial(center, px(0.0), center, rx, colors);
+        gradient.aspect = if rx.0.abs() < 1e-6 { 1.0 } else { ry.0 / rx.0 };
+        gradient
+    }
 
-            // Convert from RGBA to BGRA.
-            *pixel = image::Rgba([
-                (rgba.b * 255.) as u8,
-                (rgba.g * 255.) as u8,
-                (rgba.r * 255.) as u8,
-                (rgba.a * 255.) as u8,
-            ]);
+    /// Builds a concentric radial gradient that fills `box_size` (CSS
+    /// `farthest-corner` default), as a `Circle` or an `Ellipse` matching
+    /// the box's aspect ratio.
+    pub fn radial_shaped(
+        center: Point<Pixels>,
+        shape: RadialShape,
+        colors: Vec<ColorStop>,
+        box_size: Size<Pixels>,
+    ) -> Self {
+        let mut gradient = Self::radial_sized(center, RadialSize::FarthestCorner, colors, box_size);
+        if matches!(shape, RadialShape::Circle) {
+            gradient.aspect = 1.0;
         }
+        gradient
+    }
 
+    /// Like `radial`, but the stop pattern tiles outward past the end
+    /// circle, repeating over the span between the first and last stop
+    /// (see `wrap_repeating`), instead of clamping to the last stop.
+    pub fn repeating_radial(
+        start: Point<Pixels>,
+        start_radius: Pixels,
+        end: Point<Pixels>,
+        end_radius: Pixels,
+        colors: Vec<ColorStop>,
+    ) -> Self {
+        let mut gradient = Self::radial(start, start_radius, end, end_radius, colors);
+        gradient.gradient_type = GradientType::RepeatingRadial;
+        gradient
+    }
+
+    /// Builds a conic gradient sweeping clockwise around `start`.
+    pub fn conic(start: Point<Pixels>, colors: Vec<ColorStop>) -> Self {
+        Self {
+            colors,
+            gradient_type: GradientType::Conic,
+            start,
+            // Matches CSS's `conic-gradient()` default start angle
+            // (straight up) rotated into this module's own angle
+            // convention (0 pointing right, increasing clockwise on screen
+            // since y grows downward).
+            start_angle: -PI / 2.0,
+            ..Default::default()
+        }
+    }
+
+    /// Like `conic`, but the stop pattern tiles around the sweep, repeating
+    /// over the span between the first and last stop (see `wrap_repeating`).
+    pub fn repeating_conic(start: Point<Pixels>, colors: Vec<ColorStop>) -> Self {
+        let mut gradient = Self::conic(start, colors);
+        gradient.gradient_type = GradientType::RepeatingConic;
+        gradient
+    }
+
+    /// Sets the angle, in radians, the `Conic`/`RepeatingConic` sweep
+    /// starts from. Has no effect on other gradient types.
+    pub fn with_start_angle(mut self, start_angle: f32) -> Self {
+        self.start_angle = start_angle;
+        self
+    }
+
+    /// Makes `render` repeat this gradient across the canvas at a
+    /// `tile_size` period, leaving a transparent `tile_spacing` gap between
+    /// tiles, following WebRender's gradient tiling model. Lets callers
+    /// build striped/patterned backgrounds from one `Gradient` instead of
+    /// stacking many side by side.
+    pub fn with_tile(mut self, tile_size: Size<Pixels>, tile_spacing: Size<Pixels>) -> Self {
+        self.tile = Some((tile_size, tile_spacing));
+        self
+    }
+
+    /// Attaches a 2x3 affine transform `[a, b, c, d, e, f]` (scale, rotate,
+    /// skew, translate) to the gradient's coordinate space: `x' = a*x + c*y
+    /// + e`, `y' = b*x + d*y + f`. The matrix is inverted once here so
+    /// `calculate_t` can map incoming pixels back into the gradient's
+    /// untransformed space. A singular matrix is ignored.
+    pub fn with_transform(mut self, matrix: [f32; 6]) -> Self {
+        self.transform = invert_affine(matrix);
+        self
+    }
+
+    /// Parses a CSS `linear-gradient()` or `repeating-linear-gradient()`
+    /// string into a `Gradient` sized to fit `size`, e.g.
+    /// `"linear-gradient(45deg, red, blue 50%, #00ff0080 100%)"` or
+    /// `"linear-gradient(to top right, red, blue)"`. Lets callers drive the
+    /// renderer straight from theme/config strings instead of hand-building
+    /// `color_stop(...)` vectors.
+    pub fn from_css(css: &str, size: Size<Pixels>) -> Result<Self, String> {
+        let css = css.trim();
+        let (repeating, rest) = if let Some(rest) = css.strip_prefix("repeating-linear-gradient(")
+        {
+            (true, rest)
+        } else if let Some(rest) = css.strip_prefix("linear-gradient(") {
+            (false, rest)
+        } else {
+            return Err(format!("unsupported gradient function in `{css}`"));
+        };
+        let inner = rest
+            .strip_suffix(')')
+            .ok_or_else(|| format!("missing closing `)` in `{css}`"))?;
+
+        let mut args = split_top_level_commas(inner).into_iter();
+        let first = args.next().ok_or("gradient has no arguments")?.trim();
+
+        // `AngleOrCorner::Angle` follows `calculate_start_end`'s math
+        // convention (0deg points right, increasing clockwise on screen),
+        // which is CSS's own `<angle>` convention rotated -90deg.
+        let (angle_or_corner, first_is_direction) = if let Some(side) = first.strip_prefix("to ") {
+            (AngleOrCorner::Side(parse_side_or_corner(side.trim())?), true)
+        } else if let Some(angle) = parse_css_angle(first) {
+            (AngleOrCorner::Angle(angle - 90.0), true)
+        } else {
+            // CSS defaults to "to bottom" (180deg) when no direction is
+            // given, and `first` is actually the first color stop.
+            (AngleOrCorner::Angle(180.0 - 90.0), false)
+        };
+
+        let stops: Vec<&str> = if first_is_direction {
+            args.collect()
+        } else {
+            std::iter::once(first).chain(args).collect()
+        };
+        if stops.is_empty() {
+            return Err("gradient has no color stops".to_string());
+        }
+        let colors = stops
+            .into_iter()
+            .map(|stop| parse_css_color_stop(stop.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(if repeating {
+            Self::repeating_linear(angle_or_corner, colors, size)
+        } else {
+            Self::linear(angle_or_corner, colors, size)
+        })
+    }
+
+    /// Samples `color_for_t` at `resolution` evenly spaced points across
+    /// `[0, 1]` and converts each to the BGRA bytes `render` writes into the
+    /// image buffer, so a full-resolution render only needs to evaluate the
+    /// (cheap but not free) stop lookup + color-space interpolation once per
+    /// ramp entry instead of once per pixel.
+    fn color_ramp(&self, resolution: usize) -> Vec<Rgba<u8>> {
+        (0..resolution)
+            .map(|i| {
+                let t = i as f32 / (resolution - 1).max(1) as f32;
+                let rgba = self.color_for_t(t).to_rgb();
+                // Convert from RGBA to BGRA.
+                Rgba([
+                    (rgba.b * 255.) as u8,
+                    (rgba.g * 255.) as u8,
+                    (rgba.r * 255.) as u8,
+                    (rgba.a * 255.) as u8,
+                ])
+            })
+            .collect()
+    }
+
+    pub fn render(&self, size: Size<Pixels>) -> RenderImage {
+        let width = size.width.0 as u32;
+        let height = size.height.0 as u32;
+        let ramp = self.color_ramp(RAMP_RESOLUTION);
+
+        let mut buffer = vec![0u8; width as usize * height as usize * 4];
+        buffer
+            .par_chunks_mut(width as usize * 4)
+            .enumerate()
+            .for_each(|(y, row)| {
+                for x in 0..width as usize {
+                    let pos = match self.tile {
+                        Some((tile_size, tile_spacing)) => {
+                            let period_x = tile_size.width.0 + tile_spacing.width.0;
+                            let period_y = tile_size.height.0 + tile_spacing.height.0;
+                            let local_x = (x as f32).rem_euclid(period_x);
+                            let local_y = (y as f32).rem_euclid(period_y);
+                            if local_x >= tile_size.width.0 || local_y >= tile_size.height.0 {
+                                row[x * 4..x * 4 + 4].copy_from_slice(&[0, 0, 0, 0]);
+                                continue;
+                            }
+                            Point {
+                                x: px(local_x),
+                                y: px(local_y),
+                            }
+                        }
+                        None => Point {
+                            x: px(x as f32),
+                            y: px(y as f32),
+                        },
+                    };
+                    let t = self.calculate_t(pos);
+                    let index = (t * (ramp.len() - 1) as f32).round() as usize;
+                    row[x * 4..x * 4 + 4].copy_from_slice(&ramp[index].0);
+                }
+            });
+
+        let img = ImageBuffer::from_raw(width, height, buffer)
+            .expect("buffer is sized exactly width * height * 4");
         let data = SmallVec::from_elem(Frame::new(img), 1);
         RenderImage::new(data)
     }
@@ -104,6 +1000,7 @@ impl Gradient {
             gradient_type: GradientType::Linear,
             start,
             end,
+            ..Default::default()
         }
     }
 
@@ -118,6 +1015,7 @@ impl Gradient {
             gradient_type: GradientType::RepeatingLinear,
             start,
             end,
+            ..Default::default()
         }
     }
 
@@ -231,24 +1129,91 @@ impl Gradient {
         }
     }
 
-    fn calculate_color(&self, pos: Point<Pixels>) -> Hsla {
-        let x = pos.x;
-        let y = pos.y;
+    /// Maps a pixel position to a raw progress value along the gradient's
+    /// geometry (linear projection, two-circle radial solve, or conic
+    /// angle), wrapped or
+    /// clamped into `[0, 1]`. Split out from `color_for_t` so `render` can
+    /// call this once per pixel while still only resolving color once per
+    /// ramp entry.
+    fn calculate_t(&self, pos: Point<Pixels>) -> f32 {
+        let (x, y) = match self.transform {
+            Some(inv) => apply_affine(inv, pos.x.0, pos.y.0),
+            None => (pos.x.0, pos.y.0),
+        };
 
-        let t = match self.gradient_type {
+        let t: f32 = match self.gradient_type {
             GradientType::Linear | GradientType::RepeatingLinear => {
-                let dx = self.end.x - self.start.x;
-                let dy = self.end.y - self.start.y;
-                let dist = (dx * dx + dy * dy).0.sqrt();
-                let dot = ((x - self.start.x) * dx + (y - self.start.y) * dy) / dist;
+                let dx = self.end.x.0 - self.start.x.0;
+                let dy = self.end.y.0 - self.start.y.0;
+                let dist = (dx * dx + dy * dy).sqrt();
+                let dot = ((x - self.start.x.0) * dx + (y - self.start.y.0) * dy) / dist;
                 dot / dist
             }
+            GradientType::Radial | GradientType::RepeatingRadial => {
+                // Scale the vertical axis by `1 / aspect` around the start
+                // center (the pixel and the end center alike) so a circular
+                // solve produces an ellipse with the requested `aspect`
+                // (ry / rx) ratio.
+                let cy = self.start.y.0;
+                let squash = |v: f32| cy + (v - cy) / self.aspect;
+                solve_two_circle_t(
+                    x,
+                    squash(y),
+                    self.start.x.0,
+                    cy,
+                    self.start_radius.0,
+                    self.end.x.0,
+                    squash(self.end.y.0),
+                    self.end_radius.0,
+                )
+            }
+            GradientType::Conic | GradientType::RepeatingConic => {
+                let cx = self.start.x.0;
+                let cy = self.start.y.0;
+                ((y - cy).atan2(x - cx) - self.start_angle) / (2.0 * PI)
+            }
         };
-        let t = if matches!(self.gradient_type, GradientType::RepeatingLinear) {
-            t.0 % 1.0
+        if self.is_repeating() {
+            self.wrap_repeating(t)
+        } else if matches!(
+            self.gradient_type,
+            GradientType::Conic | GradientType::RepeatingConic
+        ) {
+            // Conic sweeps wrap continuously across the 0/2π seam even when
+            // not repeating, so the last stop blends back into the first.
+            t.rem_euclid(1.0)
         } else {
-            t.0.clamp(0.0, 1.0)
-        };
+            t.clamp(0.0, 1.0)
+        }
+    }
+
+    fn is_repeating(&self) -> bool {
+        matches!(
+            self.gradient_type,
+            GradientType::RepeatingLinear | GradientType::RepeatingRadial | GradientType::RepeatingConic
+        )
+    }
+
+    /// Wraps `t` for a repeating gradient variant. Per CSS's
+    /// repeating-gradient model, repetition spans only the interval
+    /// between the first and last stop, not the whole `[0, 1]` line
+    /// (`linear-gradient(..., red, blue 20%)` tiles every 20%, not every
+    /// 100%).
+    fn wrap_repeating(&self, t: f32) -> f32 {
+        let first = self.colors.first().and_then(|c| c.percentage).unwrap_or(0.0);
+        let last = self.colors.last().and_then(|c| c.percentage).unwrap_or(1.0);
+        let repeat_len = last - first;
+        if repeat_len.abs() < 1e-6 {
+            // All stops coincide: nothing to tile, render a solid color.
+            first
+        } else {
+            first + (t - first).rem_euclid(repeat_len)
+        }
+    }
+
+    /// Looks up the color stops bracketing `t` and blends between them in
+    /// the gradient's configured color space / hue direction.
+    fn color_for_t(&self, t: f32) -> Hsla {
         let i = self
             .colors
             .iter()
@@ -266,8 +1231,293 @@ impl Gradient {
             .map_or(1.0, |color_stop| color_stop.percentage.unwrap_or(1.0));
 
         let t = (t - start_percentage) / (end_percentage - start_percentage);
-        self.colors[i]
-            .color
-            .interpolate(self.colors[i + 1].color, t)
+        self.colors[i].color.interpolate_in(
+            self.colors[i + 1].color,
+            t,
+            self.color_space,
+            self.hue_interpolation,
+        )
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// sRGB -> OKLab -> sRGB should round-trip to within float error across
+    /// a spread of colors, including edge cases like pure black/white.
+    #[test]
+    fn oklab_round_trips_srgb() {
+        let cases = [
+            (0.0, 0.0, 0.0),
+            (1.0, 1.0, 1.0),
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0),
+            (0.2, 0.6, 0.9),
+        ];
+        for (r, g, b) in cases {
+            let (l, a, bb) = srgb_to_oklab(r, g, b);
+            let (r2, g2, b2) = oklab_to_srgb(l, a, bb);
+            assert!((r - r2).abs() < 1e-4, "r: {r} vs {r2}");
+            assert!((g - g2).abs() < 1e-4, "g: {g} vs {g2}");
+            assert!((b - b2).abs() < 1e-4, "b: {b} vs {b2}");
+        }
+    }
+
+    /// For a concentric two-circle radial (same center), `t` should reduce
+    /// to the simple `(dist - r0) / (r1 - r0)` linear case.
+    #[test]
+    fn solve_two_circle_t_concentric() {
+        let t = solve_two_circle_t(10.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 20.0);
+        assert!((t - 0.5).abs() < 1e-4, "t: {t}");
+    }
+
+    /// Offset start/end centers (a focal-point gradient) should still pick
+    /// the root where the swept circle's radius stays non-negative.
+    #[test]
+    fn solve_two_circle_t_offset_focal_point() {
+        let t = solve_two_circle_t(20.0, 0.0, -5.0, 0.0, 0.0, 5.0, 0.0, 10.0);
+        assert!(t >= 0.0, "t: {t}");
+        let radius_at_t = 0.0 + t * (10.0 - 0.0);
+        assert!(radius_at_t >= -1e-4, "radius at t should stay non-negative: {radius_at_t}");
+    }
+
+    /// Applying a transform then its inverse should be a no-op.
+    #[test]
+    fn affine_transform_round_trips() {
+        let matrix = [2.0, 0.5, -0.5, 1.5, 10.0, -4.0];
+        let inv = invert_affine(matrix).expect("matrix should be invertible");
+        let (x, y) = apply_affine(matrix, 3.0, 7.0);
+        let (x2, y2) = apply_affine(inv, x, y);
+        assert!((x2 - 3.0).abs() < 1e-4, "x: {x2}");
+        assert!((y2 - 7.0).abs() < 1e-4, "y: {y2}");
+    }
+
+    /// A singular matrix (zero determinant) has no inverse.
+    #[test]
+    fn invert_affine_rejects_singular_matrix() {
+        assert!(invert_affine([1.0, 2.0, 2.0, 4.0, 0.0, 0.0]).is_none());
+    }
+
+    /// At `t=0`/`t=1`, `interpolate_in` should return the endpoint stops
+    /// unchanged regardless of color space, since every space's lerp
+    /// collapses to one endpoint at the boundaries.
+    #[test]
+    fn interpolate_in_endpoints_match_stops_in_every_space() {
+        let red = hsla(0.0, 1.0, 0.5, 1.0);
+        let green = hsla(1.0 / 3.0, 1.0, 0.5, 1.0);
+        for color_space in [
+            ColorSpace::Srgb,
+            ColorSpace::LinearSrgb,
+            ColorSpace::Oklab,
+            ColorSpace::Oklch,
+        ] {
+            let start = red.interpolate_in(green, 0.0, color_space, HueInterpolation::Shorter);
+            let end = red.interpolate_in(green, 1.0, color_space, HueInterpolation::Shorter);
+            assert!((start.h - red.h).abs() < 1e-3, "{color_space:?} t=0 h");
+            assert!((end.h - green.h).abs() < 1e-3, "{color_space:?} t=1 h");
+        }
+    }
+
+    /// OKLab blends perceptually, so the midpoint of red->green should stay
+    /// brighter than the muddy-brown midpoint componentwise sRGB produces.
+    #[test]
+    fn oklab_midpoint_is_brighter_than_srgb_midpoint() {
+        let red = hsla(0.0, 1.0, 0.5, 1.0);
+        let green = hsla(1.0 / 3.0, 1.0, 0.5, 1.0);
+        let srgb_mid = red.interpolate_in(green, 0.5, ColorSpace::Srgb, HueInterpolation::Shorter);
+        let oklab_mid = red.interpolate_in(green, 0.5, ColorSpace::Oklab, HueInterpolation::Shorter);
+        assert!(
+            oklab_mid.l > srgb_mid.l,
+            "oklab midpoint ({}) should be lighter than srgb midpoint ({})",
+            oklab_mid.l,
+            srgb_mid.l
+        );
+    }
+
+    /// Naive `Srgb` blending dips through an overly dark, muddy middle
+    /// between saturated complementary stops (e.g. red->green) because it
+    /// averages gamma-encoded channels instead of light. `LinearSrgb`
+    /// blends in linear light, so its midpoint's relative luminance should
+    /// come out noticeably higher for the same stops.
+    #[test]
+    fn linear_srgb_midpoint_is_brighter_than_srgb_midpoint() {
+        fn relative_luminance(color: Hsla) -> f32 {
+            let rgba = color.to_rgb();
+            0.2126 * rgba.r + 0.7152 * rgba.g + 0.0722 * rgba.b
+        }
+
+        let red = hsla(0.0, 1.0, 0.5, 1.0);
+        let green = hsla(1.0 / 3.0, 1.0, 0.5, 1.0);
+        let srgb_mid = red.interpolate_in(green, 0.5, ColorSpace::Srgb, HueInterpolation::Shorter);
+        let linear_mid =
+            red.interpolate_in(green, 0.5, ColorSpace::LinearSrgb, HueInterpolation::Shorter);
+        assert!(
+            relative_luminance(linear_mid) > relative_luminance(srgb_mid),
+            "linear-light midpoint ({}) should be brighter than naive srgb midpoint ({})",
+            relative_luminance(linear_mid),
+            relative_luminance(srgb_mid)
+        );
+    }
+
+    /// `Shorter` between 350° and 10° (turns: 0.972 -> 0.028) should take
+    /// the 20° short way through 0/1, not the 340° long way.
+    #[test]
+    fn interpolate_hue_shorter_takes_short_arc() {
+        let h = interpolate_hue(350.0 / 360.0, 10.0 / 360.0, 0.5, HueInterpolation::Shorter);
+        assert!((h - 0.0).abs() < 1e-3 || (h - 1.0).abs() < 1e-3, "h: {h}");
+    }
+
+    /// `Longer` between the same two hues should instead take the 340° arc,
+    /// landing near 180° at the midpoint.
+    #[test]
+    fn interpolate_hue_longer_takes_long_arc() {
+        let h = interpolate_hue(350.0 / 360.0, 10.0 / 360.0, 0.5, HueInterpolation::Longer);
+        assert!((h - 0.5).abs() < 1e-3, "h: {h}");
+    }
+
+    /// `Increasing` always travels clockwise (hue angle only ever grows).
+    /// From 30° to 300° (`d` already positive) it should just lerp
+    /// directly, landing at the arithmetic midpoint 165°.
+    #[test]
+    fn interpolate_hue_increasing_always_wraps_forward() {
+        let h = interpolate_hue(30.0 / 360.0, 300.0 / 360.0, 0.5, HueInterpolation::Increasing);
+        assert!((h - 165.0 / 360.0).abs() < 1e-3, "h: {h}");
+    }
+
+    /// `Decreasing` always travels counterclockwise (hue angle only ever
+    /// shrinks). From 300° to 30° (`d` already negative) it should just
+    /// lerp directly, landing at the arithmetic midpoint 165°.
+    #[test]
+    fn interpolate_hue_decreasing_always_wraps_backward() {
+        let h = interpolate_hue(300.0 / 360.0, 30.0 / 360.0, 0.5, HueInterpolation::Decreasing);
+        assert!((h - 165.0 / 360.0).abs() < 1e-3, "h: {h}");
+    }
+
+    fn test_size() -> Size<Pixels> {
+        Size {
+            width: px(100.0),
+            height: px(100.0),
+        }
+    }
+
+    /// `#rrggbbaa` hex stops should parse to the exact byte-for-byte color.
+    #[test]
+    fn from_css_parses_hex_colors() {
+        let gradient = Gradient::from_css("linear-gradient(#ff000080, #00ff00)", test_size())
+            .expect("should parse");
+        assert_eq!(gradient.colors.len(), 2);
+        let first = rgb_f32_to_hsla(1.0, 0.0, 0.0, 128.0 / 255.0);
+        assert!((gradient.colors[0].color.h - first.h).abs() < 1e-3);
+        assert!((gradient.colors[0].color.a - first.a).abs() < 1e-2);
+    }
+
+    /// CSS's `<angle>` convention (0deg points up) is rotated -90deg into
+    /// this module's own convention (0deg points right) before being
+    /// stored as `AngleOrCorner::Angle`.
+    #[test]
+    fn from_css_converts_angle_convention() {
+        let gradient =
+            Gradient::from_css("linear-gradient(45deg, red, blue)", test_size()).expect("should parse");
+        let (start, end) = Gradient::calculate_start_end(
+            AngleOrCorner::Angle(45.0 - 90.0),
+            test_size(),
+        );
+        assert_eq!((gradient.start, gradient.end), (start, end));
+    }
+
+    /// `to <corner>` directions should resolve through the shared
+    /// `GradientSide` enum, not a separate CSS-only direction type.
+    #[test]
+    fn from_css_parses_to_corner() {
+        let gradient = Gradient::from_css("linear-gradient(to top right, red, blue)", test_size())
+            .expect("should parse");
+        let (start, end) =
+            Gradient::calculate_start_end(AngleOrCorner::Side(GradientSide::TopRight), test_size());
+        assert_eq!((gradient.start, gradient.end), (start, end));
+    }
+
+    /// Percentage stop positions should carry through as `0.0..=1.0`
+    /// fractions on the resulting `ColorStop`s.
+    #[test]
+    fn from_css_parses_percentage_stops() {
+        let gradient = Gradient::from_css("linear-gradient(red, blue 30%, green 80%)", test_size())
+            .expect("should parse");
+        assert_eq!(gradient.colors[0].percentage, None);
+        assert!((gradient.colors[1].percentage.unwrap() - 0.3).abs() < 1e-4);
+        assert!((gradient.colors[2].percentage.unwrap() - 0.8).abs() < 1e-4);
+    }
+
+    /// Malformed gradient functions should fail to parse instead of
+    /// panicking or silently producing a garbage gradient.
+    #[test]
+    fn from_css_rejects_unsupported_function() {
+        assert!(Gradient::from_css("radial-gradient(red, blue)", test_size()).is_err());
+    }
+
+    /// A repeating gradient whose stops span `0%..20%` should tile every
+    /// 20% of the line, not every 100% (i.e. `t=0.3` should wrap back to
+    /// the same offset as `t=0.1`).
+    #[test]
+    fn wrap_repeating_uses_stop_span_not_full_line() {
+        let gradient = Gradient::repeating_linear(
+            AngleOrCorner::Angle(0.0),
+            vec![
+                color_stop(hsla(0.0, 1.0, 0.5, 1.0), Some(0.0)),
+                color_stop(hsla(1.0 / 3.0, 1.0, 0.5, 1.0), Some(0.2)),
+            ],
+            test_size(),
+        );
+        assert!((gradient.wrap_repeating(0.3) - gradient.wrap_repeating(0.1)).abs() < 1e-4);
+        assert!((gradient.wrap_repeating(0.1) - 0.1).abs() < 1e-4);
+    }
+
+    /// Stops that all coincide at the same position have no interval to
+    /// tile; wrapping should fall back to a solid color at that position
+    /// instead of dividing by zero.
+    #[test]
+    fn wrap_repeating_handles_zero_length_interval() {
+        let gradient = Gradient::repeating_linear(
+            AngleOrCorner::Angle(0.0),
+            vec![
+                color_stop(hsla(0.0, 1.0, 0.5, 1.0), Some(0.5)),
+                color_stop(hsla(1.0 / 3.0, 1.0, 0.5, 1.0), Some(0.5)),
+            ],
+            test_size(),
+        );
+        assert!((gradient.wrap_repeating(0.9) - 0.5).abs() < 1e-4);
+    }
+
+    /// On a non-square box, `RadialShape::Ellipse` should stretch to match
+    /// the box's aspect ratio instead of collapsing to a circle.
+    #[test]
+    fn radial_shaped_ellipse_matches_box_aspect_ratio() {
+        let box_size = Size {
+            width: px(200.0),
+            height: px(100.0),
+        };
+        let center = Point {
+            x: px(100.0),
+            y: px(50.0),
+        };
+        let colors = || {
+            vec![
+                color_stop(hsla(0.0, 1.0, 0.5, 1.0), None),
+                color_stop(hsla(1.0 / 3.0, 1.0, 0.5, 1.0), None),
+            ]
+        };
+
+        let ellipse = Gradient::radial_shaped(center, RadialShape::Ellipse, colors(), box_size);
+        assert!(
+            (ellipse.aspect - 1.0).abs() > 1e-3,
+            "aspect: {}",
+            ellipse.aspect
+        );
+        assert!((ellipse.aspect - 0.5).abs() < 1e-3, "aspect: {}", ellipse.aspect);
+
+        let circle = Gradient::radial_shaped(center, RadialShape::Circle, colors(), box_size);
+        assert!((circle.aspect - 1.0).abs() < 1e-4);
     }
 }
@@ -3,16 +3,31 @@ mod gradient;
 use std::sync::Arc;
 
 use gpui::{
-    px, relative, Edges, Element, Hsla, Interactivity, IntoElement, Pixels, RenderImage, Size,
-    Style, WindowContext,
+    px, relative, Edges, Element, Hsla, Interactivity, IntoElement, Pixels, Point, RenderImage,
+    Size, Style, WindowContext,
 };
 pub use gradient::*;
 
+/// How a `GradientElement`'s geometry is derived from the element's box at
+/// paint time. `Linear` resolves `calculate_start_end` against the box
+/// each time it's painted (so resizing the element re-derives the
+/// endpoints); `RadialShaped` similarly resolves `Gradient::radial_shaped`
+/// against the box. Gradients with explicit pixel geometry (`radial()`,
+/// `conic()`) don't need an entry here since they carry their
+/// own centers/radii set at construction.
+enum Geometry {
+    Linear(AngleOrCorner),
+    RadialShaped {
+        center: Point<Pixels>,
+        shape: RadialShape,
+    },
+}
+
 /// Render A Gradient
 pub struct GradientElement {
     interactivity: Interactivity,
     base: Gradient,
-    angle_or_corner: AngleOrCorner,
+    geometry: Geometry,
     cached_size: Option<Size<Pixels>>,
     cache: Option<Arc<RenderImage>>,
 }
@@ -22,19 +37,97 @@ impl GradientElement {
         Self {
             interactivity: Interactivity::default(),
             base: Gradient::default(),
-            angle_or_corner: AngleOrCorner::Angle(0.0),
+            geometry: Geometry::Linear(AngleOrCorner::Angle(0.0)),
+            cache: None,
+            cached_size: None,
+        }
+    }
+
+    /// Builds a two-circle radial gradient element sweeping from the circle
+    /// `(start, start_radius)` to `(end, end_radius)`. Unlike `linear()`,
+    /// the geometry is explicit pixels, not derived from the element's box.
+    pub fn radial(
+        start: Point<Pixels>,
+        start_radius: Pixels,
+        end: Point<Pixels>,
+        end_radius: Pixels,
+    ) -> Self {
+        Self {
+            interactivity: Interactivity::default(),
+            base: Gradient::radial(start, start_radius, end, end_radius, Vec::new()),
+            geometry: Geometry::Linear(AngleOrCorner::Angle(0.0)),
+            cache: None,
+            cached_size: None,
+        }
+    }
+
+    /// Builds a concentric radial gradient element sized with a CSS-style
+    /// keyword relative to the element's box (`farthest-corner`, the CSS
+    /// default), as a `Circle` or an `Ellipse` matching the box's aspect
+    /// ratio. Unlike `radial()`, the box-relative sizing is deferred and
+    /// re-resolved by `render_image` each time the element's size changes.
+    pub fn radial_shaped(center: Point<Pixels>, shape: RadialShape) -> Self {
+        Self {
+            interactivity: Interactivity::default(),
+            base: Gradient::default(),
+            geometry: Geometry::RadialShaped { center, shape },
+            cache: None,
+            cached_size: None,
+        }
+    }
+
+    /// Builds a conic gradient element sweeping clockwise around `center`.
+    /// Like `radial()`, the geometry is explicit pixels, not derived from
+    /// the element's box.
+    pub fn conic(center: Point<Pixels>) -> Self {
+        Self {
+            interactivity: Interactivity::default(),
+            base: Gradient::conic(center, Vec::new()),
+            geometry: Geometry::Linear(AngleOrCorner::Angle(0.0)),
             cache: None,
             cached_size: None,
         }
     }
 
     pub fn angle(mut self, angle: f32) -> Self {
-        self.angle_or_corner = AngleOrCorner::Angle(angle);
+        self.geometry = Geometry::Linear(AngleOrCorner::Angle(angle));
         self
     }
 
     pub fn side(mut self, side: GradientSide) -> Self {
-        self.angle_or_corner = AngleOrCorner::Side(side);
+        self.geometry = Geometry::Linear(AngleOrCorner::Side(side));
+        self
+    }
+
+    /// Sets the angle, in radians, a `conic()` element's sweep starts from.
+    pub fn start_angle(mut self, start_angle: f32) -> Self {
+        self.base = self.base.with_start_angle(start_angle);
+        self
+    }
+
+    /// Sets the color space stops are blended in (default `Srgb`).
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.base = self.base.with_color_space(color_space);
+        self
+    }
+
+    /// Sets which direction around the hue wheel `Oklch` stops travel.
+    pub fn hue_interpolation(mut self, hue_interpolation: HueInterpolation) -> Self {
+        self.base = self.base.with_hue_interpolation(hue_interpolation);
+        self
+    }
+
+    /// Attaches a 2x3 affine transform `[a, b, c, d, e, f]` to the
+    /// gradient's coordinate space (see `Gradient::with_transform`).
+    pub fn transform(mut self, matrix: [f32; 6]) -> Self {
+        self.base = self.base.with_transform(matrix);
+        self
+    }
+
+    /// Tiles the gradient across the element at a `tile_size` period with a
+    /// transparent `tile_spacing` gap between tiles (see `Gradient::with_tile`).
+    pub fn tile(mut self, tile_size: Size<Pixels>, tile_spacing: Size<Pixels>) -> Self {
+        self.base = self.base.with_tile(tile_size, tile_spacing);
         self
     }
 
@@ -51,15 +144,46 @@ impl GradientElement {
     }
 
     pub fn render_image(&mut self, size: Size<Pixels>) -> Arc<RenderImage> {
-        let (start, end) = Gradient::calculate_start_end(self.angle_or_corner, size);
         if let Some(cache) = &self.cache {
             if self.cached_size == Some(size) {
                 return cache.clone();
             }
         }
 
-        self.base.start = start;
-        self.base.end = end;
+        match self.geometry {
+            // Only `Linear`/`RepeatingLinear` derive their geometry from
+            // the angle/side + the element's box; other gradient types
+            // built with explicit pixel geometry (`radial()`) are left
+            // untouched here.
+            Geometry::Linear(angle_or_corner) => {
+                if matches!(
+                    self.base.gradient_type,
+                    GradientType::Linear | GradientType::RepeatingLinear
+                ) {
+                    // When tiled, `render` evaluates every pixel in local
+                    // tile coordinates (`0..tile_size`), so the start/end
+                    // endpoints must be resolved against `tile_size`, not
+                    // the full element box, or every tile only ever
+                    // samples a sliver near the gradient's start.
+                    let geometry_size = match self.base.tile {
+                        Some((tile_size, _)) => tile_size,
+                        None => size,
+                    };
+                    let (start, end) = Gradient::calculate_start_end(angle_or_corner, geometry_size);
+                    self.base.start = start;
+                    self.base.end = end;
+                }
+            }
+            Geometry::RadialShaped { center, shape } => {
+                let mut resolved =
+                    Gradient::radial_shaped(center, shape, std::mem::take(&mut self.base.colors), size);
+                resolved.color_space = self.base.color_space;
+                resolved.hue_interpolation = self.base.hue_interpolation;
+                resolved.transform = self.base.transform;
+                resolved.tile = self.base.tile;
+                self.base = resolved;
+            }
+        }
         let image = self.base.render(size);
         let image = Arc::new(image);
         self.cached_size = Some(size);
@@ -131,3 +255,42 @@ impl Element for GradientElement {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::hsla;
+
+    /// Combining `.tile(...)` with `.angle(...)` should resolve the
+    /// gradient's start/end against `tile_size`, not the full element
+    /// box, since `Gradient::render`'s tile branch evaluates every pixel
+    /// in local tile coordinates (`0..tile_size`).
+    #[test]
+    fn tile_resolves_linear_geometry_against_tile_size_not_element_size() {
+        let tile_size = Size {
+            width: px(50.0),
+            height: px(50.0),
+        };
+        let tile_spacing = Size {
+            width: px(0.0),
+            height: px(0.0),
+        };
+        let element_size = Size {
+            width: px(1000.0),
+            height: px(1000.0),
+        };
+
+        let mut element = GradientElement::linear()
+            .angle(0.0)
+            .tile(tile_size, tile_spacing)
+            .color(hsla(0.0, 1.0, 0.5, 1.0))
+            .color(hsla(2.0 / 3.0, 1.0, 0.5, 1.0));
+
+        element.render_image(element_size);
+
+        let (expected_start, expected_end) =
+            Gradient::calculate_start_end(AngleOrCorner::Angle(0.0), tile_size);
+        assert_eq!(element.base.start, expected_start);
+        assert_eq!(element.base.end, expected_end);
+    }
+}